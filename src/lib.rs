@@ -3,40 +3,177 @@
 //! This can be useful for implementing backpressure: when accessing the item through the
 //! [`Access`] future, tasks will wait to access the item until others have completed, limiting the
 //! number of accesses that occur at the same time.
+//!
+//! ## `no_std`
+//!
+//! Disabling the default `std` feature builds this crate against `core`/`alloc` instead, backed
+//! by `event-listener`'s lock-free `no_std` implementation. This only requires a global allocator,
+//! so `AccessQueue` can be used as a backpressure primitive in embedded or SGX-style enclaves. The
+//! public API (`access`, `block`, `release`, `AccessGuard`, ...) is identical either way.
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(warnings, missing_debug_implementations, missing_docs, rust_2018_idioms)]
-use std::future::Future;
-use std::mem::ManuallyDrop;
-use std::ops::Deref;
-use std::pin::Pin;
-use std::sync::atomic::AtomicUsize;
-use std::sync::atomic::Ordering::SeqCst;
-use std::task::{Context, Poll};
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+#[cfg(not(feature = "std"))]
+use alloc::collections::VecDeque;
+
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(not(feature = "std"))]
+use core::fmt;
+
+#[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
+
+#[cfg(feature = "std")]
+use std::boxed::Box;
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+use core::cell::UnsafeCell;
+use core::future::Future;
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, AtomicUsize};
+use core::sync::atomic::Ordering::SeqCst;
+use core::task::{Context, Poll, Waker};
 
 use futures_core::ready;
 use event_listener::{Event, EventListener};
 
+use sync::Mutex;
+
+#[cfg(feature = "std")]
+mod sync {
+    pub(crate) use std::sync::Mutex;
+}
+
+// `spin::Mutex::lock` can't block without an OS to park on, so it spins; that's the standard
+// no_std substitute for `std::sync::Mutex` and is what the rest of this file is written against
+// (via the infallible `lock` below, mirroring `std::sync::Mutex::lock`'s `Result` just enough that
+// call sites don't need to fork on feature).
+#[cfg(not(feature = "std"))]
+mod sync {
+    use core::convert::Infallible;
+    use core::fmt;
+
+    pub(crate) struct Mutex<T>(spin::Mutex<T>);
+
+    impl<T> Mutex<T> {
+        pub(crate) fn new(inner: T) -> Self {
+            Mutex(spin::Mutex::new(inner))
+        }
+
+        pub(crate) fn lock(&self) -> Result<spin::MutexGuard<'_, T>, Infallible> {
+            Ok(self.0.lock())
+        }
+    }
+
+    impl<T: fmt::Debug> fmt::Debug for Mutex<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(&self.0, f)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
 
-/// The AccessQueue which guards access to some item.
+/// A queued request for some number of permits.
+///
+/// Waiters are kept in a FIFO list by [`AccessQueue`], and `release` always assigns freed permits
+/// to the front of the list first. This is what makes a large `access_many` request immune to
+/// starvation: once it reaches the front of the list, no later (even single-permit) waiter can be
+/// assigned a permit until it has accumulated its full `requested` amount.
 #[derive(Debug)]
+struct Waiter {
+    requested: usize,
+    assigned: usize,
+    waker: Option<Waker>,
+}
+
+/// The AccessQueue which guards access to some item.
 pub struct AccessQueue<T> {
     count: AtomicUsize,
+    capacity: usize,
+    waiters: Mutex<VecDeque<Arc<Mutex<Waiter>>>>,
     event: Event,
-    inner: T,
+    closed: AtomicBool,
+    inner: UnsafeCell<T>,
 }
 
+// SAFETY: every `&T` handed out (via `skip_queue`) requires `T: Sync` to be shared across
+// threads, and the single `&mut T` handed out by `ExclusiveAccessGuard` (which requires
+// holding every permit, so no other borrow is outstanding) requires `T: Send` to move across
+// threads. Both are bounded here, so it's sound to bypass the auto-trait that `UnsafeCell`
+// would otherwise block.
+unsafe impl<T: Send + Sync> Sync for AccessQueue<T> {}
+
+impl<T> fmt::Debug for AccessQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessQueue")
+            .field("count", &self.count)
+            .field("capacity", &self.capacity)
+            .field("closed", &self.closed)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The error returned when an [`Access`] or [`OwnedAccess`] resolves against a closed
+/// [`AccessQueue`].
+///
+/// Once [`close`](AccessQueue::close) has been called, every pending and future access fails with
+/// this error instead of waiting for a permit that may never come.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Closed;
+
+impl fmt::Display for Closed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the AccessQueue is closed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Closed {}
+
 impl<T> AccessQueue<T> {
     /// Construct a new `AccessQueue`, which guards the `inner` value and allows only `count`
     /// concurrent accesses to occur simultaneously.
     pub fn new(inner: T, count: usize) -> AccessQueue<T> {
         AccessQueue {
             count: AtomicUsize::new(count),
+            capacity: count,
+            waiters: Mutex::new(VecDeque::new()),
             event: Event::new(),
-            inner,
+            closed: AtomicBool::new(false),
+            inner: UnsafeCell::new(inner),
         }
     }
 
+    /// Permanently close the queue.
+    ///
+    /// Every pending [`Access`]/[`OwnedAccess`] that has not yet been assigned its full request
+    /// wakes up and resolves to `Err(Closed)`, and every future call to `access`/`access_many`
+    /// (and their owned counterparts) does the same instead of waiting for a permit. Guards that
+    /// have already resolved are unaffected and still release their permits normally when
+    /// dropped. Calling `close` more than once has no additional effect.
+    pub fn close(&self) {
+        self.closed.store(true, SeqCst);
+        self.event.notify(usize::MAX);
+    }
+
+    /// Returns `true` if [`close`](AccessQueue::close) has been called on this queue.
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(SeqCst)
+    }
+
     /// Block `amt` accesses.
     ///
     /// This reduces the number of concurrent accesses to the guarded item that are allowed. Until
@@ -58,23 +195,144 @@ impl<T> AccessQueue<T> {
     /// can be paired with `block` to raise and lower the limit.
     pub fn release(&self, amt: usize) {
         self.count.fetch_add(amt, SeqCst);
+        self.assign_waiters();
         self.event.notify_additional(amt);
     }
 
+    /// Walk the FIFO waiter list from the front, handing out permits from `count`.
+    ///
+    /// The front waiter is topped up first; only once it has accumulated its full `requested`
+    /// amount (and is popped) do later waiters get a chance. A waiter that needs more permits than
+    /// are currently available keeps its partial assignment and blocks the rest of the list, so
+    /// permits already earmarked for it are never handed to a waiter behind it.
+    fn assign_waiters(&self) {
+        let mut waiters = self.waiters.lock().unwrap();
+        while let Some(front) = waiters.front().cloned() {
+            let mut waiter = front.lock().unwrap();
+            let needed = waiter.requested - waiter.assigned;
+            if needed == 0 {
+                drop(waiter);
+                waiters.pop_front();
+                continue;
+            }
+
+            let mut current = self.count.load(SeqCst);
+            let took = loop {
+                if current == 0 {
+                    break 0;
+                }
+                let take = needed.min(current);
+                match self.count.compare_exchange_weak(current, current - take, SeqCst, SeqCst) {
+                    Ok(_)   => break take,
+                    Err(n)  => current = n,
+                }
+            };
+
+            if took == 0 {
+                return;
+            }
+
+            waiter.assigned += took;
+            let done = waiter.assigned == waiter.requested;
+            let waker = if done { waiter.waker.take() } else { None };
+            drop(waiter);
+
+            if done {
+                waiters.pop_front();
+            }
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+            if !done {
+                // The front waiter is still short; nothing left over for waiters behind it.
+                return;
+            }
+        }
+    }
+
     /// Wait in the queue to access the guarded item.
+    ///
+    /// Resolves to `Err(Closed)` instead of waiting if the queue has been, or is, [`close`]d.
+    ///
+    /// [`close`]: AccessQueue::close
     pub fn access(&self) -> Access<'_, T> {
+        self.access_many(1)
+    }
+
+    /// Wait in the queue to reserve `amt` accesses at once.
+    ///
+    /// The returned [`AccessGuard`] releases all `amt` accesses together when it drops. Waiters
+    /// are served in FIFO order, so a large `amt` is not starved by a continual stream of smaller
+    /// requests: once this request reaches the front of the queue it keeps accumulating permits,
+    /// and no later waiter can be assigned a permit until it is fully satisfied.
+    ///
+    /// Resolves to `Err(Closed)` instead of waiting if the queue has been, or is, [`close`]d.
+    ///
+    /// [`close`]: AccessQueue::close
+    pub fn access_many(&self, amt: usize) -> Access<'_, T> {
         Access {
             queue: self,
+            amt,
+            waiter: None,
             listener: None,
         }
     }
 
+    /// Wait in the queue for shared (read) access to the guarded item.
+    ///
+    /// This is an alias of [`access`](AccessQueue::access): many shared guards may be held
+    /// concurrently. See [`access_exclusive`](AccessQueue::access_exclusive) for exclusive access.
+    pub fn access_shared(&self) -> Access<'_, T> {
+        self.access()
+    }
+
+    /// Wait in the queue for exclusive (write) access to the guarded item.
+    ///
+    /// An exclusive access is implemented as a request for the queue's full original capacity, so
+    /// it waits until every currently-held shared guard has dropped. Because it goes through the
+    /// same FIFO batch-fairness machinery as [`access_many`](AccessQueue::access_many), once an
+    /// exclusive request reaches the front of the queue no shared request behind it can be served
+    /// until it is -- a writer is never starved by a continual stream of new readers, with no
+    /// separate priority flag needed.
+    pub fn access_exclusive(&self) -> ExclusiveAccess<'_, T> {
+        ExclusiveAccess { inner: self.access_many(self.capacity) }
+    }
+
+    /// Wait in the queue to access the guarded item, giving up if `timeout` resolves first.
+    ///
+    /// `timeout` is any future the caller supplies as the deadline signal (for example, a
+    /// runtime's `sleep` future) -- this crate does not depend on a particular timer. If the
+    /// access does not resolve before `timeout` does, this resolves to
+    /// `Err(AccessTimeoutError::Timeout)`, and no permit is consumed: any permits this request had
+    /// already accumulated are handed back to the next waiters in line, the same as if the
+    /// `Access` had simply been dropped.
+    pub fn access_timeout<F>(&self, timeout: F) -> AccessTimeout<'_, T, F>
+    where
+        F: Future<Output = ()> + Unpin,
+    {
+        self.access_many_timeout(1, timeout)
+    }
+
+    /// Wait in the queue to reserve `amt` accesses at once, giving up if `timeout` resolves first.
+    ///
+    /// See [`access_timeout`](AccessQueue::access_timeout) for the timeout semantics.
+    pub fn access_many_timeout<F>(&self, amt: usize, timeout: F) -> AccessTimeout<'_, T, F>
+    where
+        F: Future<Output = ()> + Unpin,
+    {
+        AccessTimeout {
+            access: self.access_many(amt),
+            timer: timeout,
+        }
+    }
+
     /// Skip the access queue and get a reference to the inner item.
     ///
     /// This does not modify the number of simultaneous accesses allowed. It can be useful if the
     /// AccessQueue is only limited certain patterns of use on the inner item.
     pub fn skip_queue(&self) -> &T {
-        &self.inner
+        // SAFETY: derived straight from the `UnsafeCell`, never by casting an existing `&T`.
+        unsafe { &*self.inner.get() }
     }
 
     /// Get the inner item mutably.
@@ -82,18 +340,141 @@ impl<T> AccessQueue<T> {
     /// This requires mutable access to the AccessQueue, guaranteeing that no simultaneous accesses
     /// are occurring.
     pub fn get_mut(&mut self) -> &mut T {
-        &mut self.inner
+        self.inner.get_mut()
+    }
+
+    /// Wait in the queue to access the guarded item, without borrowing the queue.
+    ///
+    /// This is the same as [`access`](AccessQueue::access), except that the returned
+    /// [`OwnedAccess`] future (and the [`OwnedAccessGuard`] it resolves to) holds its own clone of
+    /// the `Arc` rather than borrowing the queue. This lets the guard be moved into a
+    /// `tokio::spawn`ed task or stored in a `'static` future.
+    ///
+    /// Resolves to `Err(Closed)` instead of waiting if the queue has been, or is, [`close`]d.
+    ///
+    /// [`close`]: AccessQueue::close
+    pub fn access_owned(self: &Arc<Self>) -> OwnedAccess<T> {
+        self.access_many_owned(1)
+    }
+
+    /// Wait in the queue to reserve `amt` accesses at once, without borrowing the queue.
+    ///
+    /// This is the owned counterpart of [`access_many`](AccessQueue::access_many); see
+    /// [`access_owned`](AccessQueue::access_owned) for why you'd want an owned guard.
+    pub fn access_many_owned(self: &Arc<Self>, amt: usize) -> OwnedAccess<T> {
+        OwnedAccess {
+            queue: self.clone(),
+            amt,
+            waiter: None,
+            listener: None,
+        }
+    }
+}
+
+// The guts of `Access::poll`/`OwnedAccess::poll`, factored out since the two are otherwise
+// near-identical: both register a waiter, wait for it to accumulate `amt` permits (bailing out
+// early if the queue is closed), and race an `EventListener` against the queue's `Event` while
+// they wait. Taking `&AccessQueue<T>` rather than `&Access<T>`/`&OwnedAccess<T>` lets both share
+// this without caring whether the caller borrows the queue or owns an `Arc` to it.
+fn poll_waiter<T>(
+    queue: &AccessQueue<T>,
+    amt: usize,
+    waiter: &mut Option<Arc<Mutex<Waiter>>>,
+    listener: &mut Option<Pin<Box<EventListener>>>,
+    ctx: &mut Context<'_>,
+) -> Poll<Result<(), Closed>> {
+    if waiter.is_none() {
+        // Check before registering a waiter, not just in the loop below: otherwise a fresh
+        // access against an already-closed queue could still succeed if a permit happened to
+        // already be free, instead of always failing as `close`'s contract promises.
+        if queue.is_closed() {
+            return Poll::Ready(Err(Closed));
+        }
+
+        let new_waiter = Arc::new(Mutex::new(Waiter {
+            requested: amt,
+            assigned: 0,
+            waker: Some(ctx.waker().clone()),
+        }));
+        queue.waiters.lock().unwrap().push_back(new_waiter.clone());
+        *waiter = Some(new_waiter);
+        queue.assign_waiters();
+    }
+
+    loop {
+        {
+            let w = waiter.as_ref().unwrap();
+            let mut w = w.lock().unwrap();
+            if w.assigned == w.requested {
+                drop(w);
+                *waiter = None;
+                return Poll::Ready(Ok(()));
+            }
+            w.waker = Some(ctx.waker().clone());
+        }
+
+        if queue.is_closed() {
+            abandon_waiter(queue, waiter);
+            return Poll::Ready(Err(Closed));
+        }
+
+        match &mut *listener {
+            Some(l)  => {
+                ready!(l.as_mut().poll(ctx));
+                *listener = None;
+            }
+            None     => {
+                let mut new_listener = queue.event.listen();
+                if new_listener.as_mut().poll(ctx).is_pending() {
+                    // Re-check after registering, not just before: `close`'s `notify` only
+                    // reaches listeners that already existed when it ran, so if the queue closed
+                    // in the gap between our `is_closed()` check above and this registration,
+                    // that notification is lost and we'd otherwise park forever.
+                    if queue.is_closed() {
+                        abandon_waiter(queue, waiter);
+                        return Poll::Ready(Err(Closed));
+                    }
+                    *listener = Some(new_listener);
+                    return Poll::Pending
+                }
+            }
+        }
+    }
+}
+
+// Remove a waiter from the queue, handing back any permits it had already accumulated so they
+// are not lost to the waiters behind it. Shared by `Drop`, by the closed-queue path in
+// `poll_waiter` and by the expired-timeout path in `AccessTimeout::poll`, where we give up before
+// the waiter is ever naturally popped.
+fn abandon_waiter<T>(queue: &AccessQueue<T>, waiter: &mut Option<Arc<Mutex<Waiter>>>) {
+    if let Some(waiter) = waiter.take() {
+        let assigned = {
+            let mut waiter = waiter.lock().unwrap();
+            let assigned = waiter.assigned;
+            // Mark the waiter as already-satisfied so `assign_waiters` drops it from the
+            // list the next time it walks past, rather than letting it block the queue.
+            waiter.requested = 0;
+            waiter.assigned = 0;
+            assigned
+        };
+        if assigned > 0 {
+            // Hand the permits we had already accumulated back to the next waiters in line.
+            queue.release(assigned);
+        }
     }
 }
 
 /// A `Future` of a queued access to the inner item.
 ///
-/// This can be constructed from [`AccessQueue::access`]. It is a `Future`, and it resolves to an
-/// [`AccessGuard`], which dereferences to the inner item guarded by the access queue.
+/// This can be constructed from [`AccessQueue::access`] or [`AccessQueue::access_many`]. It is a
+/// `Future`, and it resolves to `Ok` with an [`AccessGuard`], which dereferences to the inner item
+/// guarded by the access queue, or to `Err(Closed)` if the queue is closed.
 #[derive(Debug)]
 pub struct Access<'a, T> {
     queue: &'a AccessQueue<T>,
-    listener: Option<EventListener>,
+    amt: usize,
+    waiter: Option<Arc<Mutex<Waiter>>>,
+    listener: Option<Pin<Box<EventListener>>>,
 }
 
 impl<'a, T> Access<'a, T> {
@@ -107,31 +488,20 @@ impl<'a, T> Access<'a, T> {
 }
 
 impl<'a, T> Future for Access<'a, T> {
-    type Output = AccessGuard<'a, T>;
+    type Output = Result<AccessGuard<'a, T>, Closed>;
 
-    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
-        if let Some(listener) = &mut self.listener {
-            ready!(Pin::new(listener).poll(ctx));
-            self.listener = None;
-        }
-
-        while !self.queue.block(1) {
-            match &mut self.listener {
-                Some(listener)  => {
-                    ready!(Pin::new(listener).poll(ctx));
-                    self.listener = None;
-                }
-                None            => {
-                    let mut listener = self.queue.event.listen();
-                    if let Poll::Pending = Pin::new(&mut listener).poll(ctx) {
-                        self.listener = Some(listener);
-                        return Poll::Pending
-                    }
-                }
-            }
-        }
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let queue = self.queue;
+        let amt = self.amt;
+        let this = self.get_mut();
+        poll_waiter(queue, amt, &mut this.waiter, &mut this.listener, ctx)
+            .map(|result| result.map(|()| AccessGuard { queue, amt }))
+    }
+}
 
-        Poll::Ready(AccessGuard { queue: self.queue })
+impl<'a, T> Drop for Access<'a, T> {
+    fn drop(&mut self) {
+        abandon_waiter(self.queue, &mut self.waiter);
     }
 }
 
@@ -139,6 +509,7 @@ impl<'a, T> Future for Access<'a, T> {
 #[derive(Debug)]
 pub struct AccessGuard<'a, T> {
     queue: &'a AccessQueue<T>,
+    amt: usize,
 }
 
 impl<'a, T> AccessGuard<'a, T> {
@@ -150,6 +521,12 @@ impl<'a, T> AccessGuard<'a, T> {
     pub fn hold_indefinitely(self) -> &'a T {
         ManuallyDrop::new(self).queue.skip_queue()
     }
+
+    // Used by `ExclusiveAccess` to convert a fully-assigned `access_many(capacity)` guard into an
+    // `ExclusiveAccessGuard` without releasing the permits it already holds.
+    fn into_queue(self) -> &'a AccessQueue<T> {
+        ManuallyDrop::new(self).queue
+    }
 }
 
 impl<'a, T> Deref for AccessGuard<'a, T> {
@@ -162,7 +539,198 @@ impl<'a, T> Deref for AccessGuard<'a, T> {
 
 impl<'a, T> Drop for AccessGuard<'a, T> {
     fn drop(&mut self) {
-        self.queue.release(1);
+        self.queue.release(self.amt);
+    }
+}
+
+/// An owned `Future` of a queued access to the inner item.
+///
+/// This can be constructed from [`AccessQueue::access_owned`] or
+/// [`AccessQueue::access_many_owned`]. It is the owned counterpart of [`Access`]: it holds its own
+/// `Arc` clone of the queue instead of borrowing it, so it is not tied to the queue's lifetime. It
+/// resolves to `Ok` with an [`OwnedAccessGuard`], or to `Err(Closed)` if the queue is closed.
+#[derive(Debug)]
+pub struct OwnedAccess<T> {
+    queue: Arc<AccessQueue<T>>,
+    amt: usize,
+    waiter: Option<Arc<Mutex<Waiter>>>,
+    listener: Option<Pin<Box<EventListener>>>,
+}
+
+impl<T> OwnedAccess<T> {
+    /// Access the guarded item without waiting in the `AccessQueue`.
+    ///
+    /// This can be used to access the item without following the limitations on the number of
+    /// allowed concurrent accesses.
+    pub fn skip_queue(&self) -> &T {
+        self.queue.skip_queue()
+    }
+}
+
+impl<T> Future for OwnedAccess<T> {
+    type Output = Result<OwnedAccessGuard<T>, Closed>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let amt = self.amt;
+        let this = self.get_mut();
+        poll_waiter(&this.queue, amt, &mut this.waiter, &mut this.listener, ctx)
+            .map(|result| result.map(|()| OwnedAccessGuard { queue: this.queue.clone(), amt }))
+    }
+}
+
+impl<T> Drop for OwnedAccess<T> {
+    fn drop(&mut self) {
+        abandon_waiter(&self.queue, &mut self.waiter);
+    }
+}
+
+/// A resolved access to the guarded item that owns its `Arc` clone of the queue.
+///
+/// This is the owned counterpart of [`AccessGuard`]; see [`AccessQueue::access_owned`] for why
+/// you'd want one. Keep this guard (often in a struct field named `_permit`) alive for as long as
+/// the detached task or boxed future needs the reservation.
+#[derive(Debug)]
+pub struct OwnedAccessGuard<T> {
+    queue: Arc<AccessQueue<T>>,
+    amt: usize,
+}
+
+impl<T> OwnedAccessGuard<T> {
+    /// Hold this guard indefinitely, without ever releasing it.
+    ///
+    /// Normaly, when an `OwnedAccessGuard` drops, it releases its accesses in the `AccessQueue` so
+    /// that other waiters can resolve. If this method is called, the access is never released.
+    pub fn hold_indefinitely(self) -> Arc<AccessQueue<T>> {
+        ManuallyDrop::new(self).queue.clone()
+    }
+}
+
+impl<T> Deref for OwnedAccessGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.queue.skip_queue()
+    }
+}
+
+impl<T> Drop for OwnedAccessGuard<T> {
+    fn drop(&mut self) {
+        self.queue.release(self.amt);
+    }
+}
+
+/// A `Future` of queued exclusive (write) access to the guarded item.
+///
+/// This can be constructed from [`AccessQueue::access_exclusive`]. It resolves to `Ok` with an
+/// [`ExclusiveAccessGuard`], which dereferences mutably to the inner item, or to `Err(Closed)` if
+/// the queue is closed.
+#[derive(Debug)]
+pub struct ExclusiveAccess<'a, T> {
+    inner: Access<'a, T>,
+}
+
+impl<'a, T> Future for ExclusiveAccess<'a, T> {
+    type Output = Result<ExclusiveAccessGuard<'a, T>, Closed>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        match Pin::new(&mut self.inner).poll(ctx) {
+            Poll::Ready(Ok(guard))  => Poll::Ready(Ok(ExclusiveAccessGuard { queue: guard.into_queue() })),
+            Poll::Ready(Err(e))     => Poll::Ready(Err(e)),
+            Poll::Pending           => Poll::Pending,
+        }
+    }
+}
+
+/// A resolved exclusive access to the guarded item.
+///
+/// Because an exclusive access holds every permit in the queue, no shared guard can be
+/// outstanding while this guard exists, which makes it sound to dereference mutably.
+#[derive(Debug)]
+pub struct ExclusiveAccessGuard<'a, T> {
+    queue: &'a AccessQueue<T>,
+}
+
+impl<'a, T> Deref for ExclusiveAccessGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.queue.skip_queue()
+    }
+}
+
+impl<'a, T> DerefMut for ExclusiveAccessGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: this guard was only issued once it had accumulated all `capacity` permits, so
+        // no shared `AccessGuard` can be concurrently dereferencing `inner`. Derived straight from
+        // the `UnsafeCell`, never by casting an existing `&T` (which would be UB).
+        unsafe { &mut *self.queue.inner.get() }
+    }
+}
+
+impl<'a, T> Drop for ExclusiveAccessGuard<'a, T> {
+    fn drop(&mut self) {
+        self.queue.release(self.queue.capacity);
+    }
+}
+
+/// The error returned by [`AccessQueue::access_timeout`]/[`AccessQueue::access_many_timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessTimeoutError {
+    /// The `timeout` future resolved before a permit was assigned.
+    Timeout,
+    /// The queue was [closed](AccessQueue::close) before a permit was assigned.
+    Closed,
+}
+
+impl fmt::Display for AccessTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessTimeoutError::Timeout    => f.write_str("timed out waiting for access"),
+            AccessTimeoutError::Closed     => f.write_str("the AccessQueue is closed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AccessTimeoutError {}
+
+/// A `Future` of a queued access that gives up once a user-supplied `timeout` future resolves.
+///
+/// This can be constructed from [`AccessQueue::access_timeout`] or
+/// [`AccessQueue::access_many_timeout`]. Every poll races the underlying [`Access`] against
+/// `timeout`; if `timeout` wins, it returns any permits it had already accumulated to the next
+/// waiters in line and drops its `EventListener` (rather than leaving it to absorb a wake meant for
+/// someone else) before resolving to `Err(AccessTimeoutError::Timeout)`.
+#[derive(Debug)]
+pub struct AccessTimeout<'a, T, F> {
+    access: Access<'a, T>,
+    timer: F,
+}
+
+impl<'a, T, F> Future for AccessTimeout<'a, T, F>
+where
+    F: Future<Output = ()> + Unpin,
+{
+    type Output = Result<AccessGuard<'a, T>, AccessTimeoutError>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        match Pin::new(&mut self.access).poll(ctx) {
+            Poll::Ready(Ok(guard))  => return Poll::Ready(Ok(guard)),
+            Poll::Ready(Err(Closed)) => return Poll::Ready(Err(AccessTimeoutError::Closed)),
+            Poll::Pending           => {}
+        }
+
+        match Pin::new(&mut self.timer).poll(ctx) {
+            Poll::Ready(())  => {
+                // Give up at once rather than waiting for this future to be dropped: hand back any
+                // permits already accumulated and drop the listener so neither outlives the timeout.
+                let this = &mut *self;
+                abandon_waiter(this.access.queue, &mut this.access.waiter);
+                this.access.listener = None;
+                Poll::Ready(Err(AccessTimeoutError::Timeout))
+            }
+            Poll::Pending    => Poll::Pending,
+        }
     }
 }
 
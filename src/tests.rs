@@ -1,5 +1,10 @@
 use super::*;
 
+#[cfg(feature = "std")]
+use std::vec::Vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 #[test]
 fn test_block() {
     let queue = AccessQueue::new((), 2);
@@ -76,7 +81,7 @@ fn reenqueue() {
     let a2 = Pin::new(&mut a2_f).poll(&mut ctx);
     assert!(matches!(&a2, &Poll::Pending));
 
-    if let Poll::Ready(a1) = a1 { a1.reenqueue(); } else { unreachable!() }
+    if let Poll::Ready(a1) = a1 { drop(a1.unwrap()); } else { unreachable!() }
 
     let a2 = Pin::new(&mut a2_f).poll(&mut ctx);
     assert!(matches!(&a2, &Poll::Ready(_)));
@@ -90,6 +95,244 @@ fn reenqueue() {
     assert!(matches!(&a1, &Poll::Ready(_)));
 }
 
+// Manually drives several `Access` futures through specific interleavings of polls and
+// releases, simulating the kind of concurrent schedules a loom model-checker would explore, to
+// pin down the starvation-freedom invariant: a large `access_many` request is not starved by a
+// continual stream of smaller ones once it is queued ahead of them.
+#[test]
+fn large_request_not_starved_by_small_ones() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = AccessQueue::new((), 0);
+
+    let mut big_f = queue.access_many(3);
+    assert!(matches!(Pin::new(&mut big_f).poll(&mut ctx), Poll::Pending));
+
+    // A stream of single-permit waiters queue up behind the big request.
+    let mut small_fs: Vec<_> = (0..5).map(|_| queue.access()).collect();
+    for small_f in &mut small_fs {
+        assert!(matches!(Pin::new(small_f).poll(&mut ctx), Poll::Pending));
+    }
+
+    // Trickle in permits one at a time: each should be assigned to the front (big) waiter
+    // rather than leaking through to any of the smaller waiters behind it.
+    queue.release(1);
+    assert!(matches!(Pin::new(&mut big_f).poll(&mut ctx), Poll::Pending));
+    for small_f in &mut small_fs {
+        assert!(matches!(Pin::new(small_f).poll(&mut ctx), Poll::Pending));
+    }
+
+    queue.release(1);
+    assert!(matches!(Pin::new(&mut big_f).poll(&mut ctx), Poll::Pending));
+    for small_f in &mut small_fs {
+        assert!(matches!(Pin::new(small_f).poll(&mut ctx), Poll::Pending));
+    }
+
+    // The third permit completes the big request before any small one is served. Hold the
+    // resolved guard in a local rather than letting it drop immediately, since dropping it here
+    // would release its permits straight back to the small waiters behind it.
+    queue.release(1);
+    let big = Pin::new(&mut big_f).poll(&mut ctx);
+    assert!(matches!(&big, &Poll::Ready(_)));
+    for small_f in &mut small_fs {
+        assert!(matches!(Pin::new(small_f).poll(&mut ctx), Poll::Pending));
+    }
+
+    // Now that the big waiter is out of the way, the small waiters can proceed one at a time.
+    drop(big);
+    for small_f in &mut small_fs {
+        queue.release(1);
+        assert!(matches!(Pin::new(small_f).poll(&mut ctx), Poll::Ready(_)));
+    }
+}
+
+#[test]
+fn cancelled_access_returns_partial_permits() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = AccessQueue::new((), 0);
+
+    let mut big_f = queue.access_many(3);
+    assert!(matches!(Pin::new(&mut big_f).poll(&mut ctx), Poll::Pending));
+
+    queue.release(2);
+    assert!(matches!(Pin::new(&mut big_f).poll(&mut ctx), Poll::Pending));
+
+    // Dropping the still-pending big request must hand its two accumulated permits back.
+    drop(big_f);
+
+    let mut small_f = queue.access_many(2);
+    assert!(matches!(Pin::new(&mut small_f).poll(&mut ctx), Poll::Ready(_)));
+}
+
+#[test]
+fn closed_queue_fails_pending_access() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = AccessQueue::new((), 0);
+
+    let mut a1_f = queue.access();
+    assert!(matches!(Pin::new(&mut a1_f).poll(&mut ctx), Poll::Pending));
+
+    queue.close();
+    assert!(queue.is_closed());
+
+    assert!(matches!(Pin::new(&mut a1_f).poll(&mut ctx), Poll::Ready(Err(Closed))));
+}
+
+#[test]
+fn closed_queue_fails_future_access() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = AccessQueue::new((), 1);
+
+    queue.close();
+
+    let mut a1_f = queue.access();
+    assert!(matches!(Pin::new(&mut a1_f).poll(&mut ctx), Poll::Ready(Err(Closed))));
+}
+
+#[test]
+fn closing_does_not_revoke_accumulated_permits() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = AccessQueue::new((), 0);
+
+    let mut a1_f = queue.access_many(2);
+    queue.release(2);
+
+    // The big request is already fully satisfied before `close` is ever called.
+    let a1 = Pin::new(&mut a1_f).poll(&mut ctx);
+    assert!(matches!(&a1, &Poll::Ready(Ok(_))));
+
+    queue.close();
+
+    // The guard resolved before `close` still releases both of its permits normally.
+    drop(a1);
+    assert_eq!(queue.count.load(SeqCst), 2);
+}
+
+#[test]
+fn exclusive_access_waits_for_shared_guards_to_drop() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = AccessQueue::new(0, 2);
+
+    let mut r1_f = queue.access_shared();
+    let r1 = Pin::new(&mut r1_f).poll(&mut ctx);
+    assert!(matches!(&r1, &Poll::Ready(Ok(_))));
+    let r1 = match r1 { Poll::Ready(guard) => guard.unwrap(), _ => unreachable!() };
+
+    let mut r2_f = queue.access_shared();
+    let r2 = Pin::new(&mut r2_f).poll(&mut ctx);
+    assert!(matches!(&r2, &Poll::Ready(Ok(_))));
+    let r2 = match r2 { Poll::Ready(guard) => guard.unwrap(), _ => unreachable!() };
+
+    let mut w_f = queue.access_exclusive();
+    assert!(matches!(Pin::new(&mut w_f).poll(&mut ctx), Poll::Pending));
+
+    drop(r1);
+    assert!(matches!(Pin::new(&mut w_f).poll(&mut ctx), Poll::Pending));
+
+    drop(r2);
+    let w = Pin::new(&mut w_f).poll(&mut ctx);
+    assert!(matches!(&w, &Poll::Ready(Ok(_))));
+}
+
+#[test]
+fn exclusive_access_blocks_new_shared_access() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = AccessQueue::new((), 1);
+
+    let mut r1_f = queue.access_shared();
+    let r1 = Pin::new(&mut r1_f).poll(&mut ctx);
+    assert!(matches!(&r1, &Poll::Ready(Ok(_))));
+    let r1 = match r1 { Poll::Ready(guard) => guard.unwrap(), _ => unreachable!() };
+
+    let mut w_f = queue.access_exclusive();
+    assert!(matches!(Pin::new(&mut w_f).poll(&mut ctx), Poll::Pending));
+
+    // A new shared request queued behind the writer cannot jump ahead of it.
+    let mut r2_f = queue.access_shared();
+    assert!(matches!(Pin::new(&mut r2_f).poll(&mut ctx), Poll::Pending));
+
+    drop(r1);
+    // Hold the resolved writer guard in a local: dropping it immediately would release its
+    // permit straight back to `r2_f`, which is exactly what this test is checking doesn't happen.
+    let w = Pin::new(&mut w_f).poll(&mut ctx);
+    assert!(matches!(&w, &Poll::Ready(Ok(_))));
+    assert!(matches!(Pin::new(&mut r2_f).poll(&mut ctx), Poll::Pending));
+}
+
+#[test]
+fn exclusive_guard_derefs_mutably() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = AccessQueue::new(0, 1);
+
+    let mut w_f = queue.access_exclusive();
+    let w = Pin::new(&mut w_f).poll(&mut ctx);
+    let mut w = match w { Poll::Ready(guard) => guard.unwrap(), _ => unreachable!() };
+    *w += 1;
+    assert_eq!(*w, 1);
+}
+
+// Minimal stand-ins for a runtime's timer future, so the timeout tests can deterministically
+// control whether the deadline has elapsed without depending on an actual timer.
+struct Pending;
+
+impl Future for Pending {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _ctx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+struct ReadyNow;
+
+impl Future for ReadyNow {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _ctx: &mut Context<'_>) -> Poll<()> {
+        Poll::Ready(())
+    }
+}
+
+#[test]
+fn access_timeout_resolves_before_deadline() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = AccessQueue::new((), 1);
+
+    let mut f = queue.access_timeout(Pending);
+    assert!(matches!(Pin::new(&mut f).poll(&mut ctx), Poll::Ready(Ok(_))));
+}
+
+#[test]
+fn access_timeout_expires_without_consuming_a_permit() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = AccessQueue::new((), 0);
+
+    let mut f = queue.access_timeout(ReadyNow);
+    assert!(matches!(
+        Pin::new(&mut f).poll(&mut ctx),
+        Poll::Ready(Err(AccessTimeoutError::Timeout))
+    ));
+    drop(f);
+
+    // No permit was consumed or leaked: a fresh access still sees the queue as empty.
+    let mut f2 = queue.access_timeout(Pending);
+    assert!(matches!(Pin::new(&mut f2).poll(&mut ctx), Poll::Pending));
+    queue.release(1);
+    assert!(matches!(Pin::new(&mut f2).poll(&mut ctx), Poll::Ready(Ok(_))));
+}
+
+#[test]
+fn access_timeout_on_closed_queue_reports_closed() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = AccessQueue::new((), 0);
+    queue.close();
+
+    let mut f = queue.access_timeout(Pending);
+    assert!(matches!(
+        Pin::new(&mut f).poll(&mut ctx),
+        Poll::Ready(Err(AccessTimeoutError::Closed))
+    ));
+}
+
 #[test]
 fn hold_indefinitely_does_not_release() {
     let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
@@ -104,8 +347,25 @@ fn hold_indefinitely_does_not_release() {
     let a2 = Pin::new(&mut a2_f).poll(&mut ctx);
     assert!(matches!(&a2, &Poll::Pending));
 
-    if let Poll::Ready(a1) = a1 { a1.hold_indefinitely(); } else { unreachable!() }
+    if let Poll::Ready(a1) = a1 { a1.unwrap().hold_indefinitely(); } else { unreachable!() }
 
     let a2 = Pin::new(&mut a2_f).poll(&mut ctx);
     assert!(matches!(&a2, &Poll::Pending))
 }
+
+#[test]
+fn owned_access_does_not_borrow_queue() {
+    let mut ctx = Context::from_waker(futures_task::noop_waker_ref());
+    let queue = Arc::new(AccessQueue::new((), 1));
+
+    let mut a1_f = queue.access_owned();
+    let a1 = Pin::new(&mut a1_f).poll(&mut ctx);
+    assert!(matches!(&a1, &Poll::Ready(_)));
+
+    // The guard outlives any borrow of `queue` and can be moved around freely.
+    let guard = match a1 { Poll::Ready(guard) => guard.unwrap(), _ => unreachable!() };
+    drop(queue);
+
+    let mut a2_f = guard.hold_indefinitely().access_owned();
+    assert!(matches!(Pin::new(&mut a2_f).poll(&mut ctx), Poll::Pending));
+}